@@ -22,27 +22,52 @@ impl From<ParseFloatError> for LexingError {
     }
 }
 
-fn callback_integer(lex: &mut logos::Lexer<Token>) -> Result<isize, LexingError> {
+fn callback_integer<'src>(lex: &mut logos::Lexer<'src, Token<'src>>) -> Result<isize, LexingError> {
     let slice = lex.slice().replace("_", "");
     slice.parse().map_err(LexingError::from)
 }
 
-fn callback_float(lex: &mut logos::Lexer<Token>) -> Result<f64, LexingError> {
-    let slice = lex.slice();
+fn callback_radix_integer<'src>(
+    lex: &mut logos::Lexer<'src, Token<'src>>,
+) -> Result<isize, LexingError> {
+    // Split off an optional sign: `from_str_radix` rejects a leading `+`/`-`.
+    let (sign, body) = match lex.slice().strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, lex.slice().strip_prefix('+').unwrap_or(lex.slice())),
+    };
+    let radix = match body.as_bytes()[1] {
+        b'x' | b'X' => 16,
+        b'o' | b'O' => 8,
+        b'b' | b'B' => 2,
+        _ => unreachable!("radix prefix is guaranteed by the regex"),
+    };
+    let magnitude = isize::from_str_radix(&body[2..].replace("_", ""), radix)?;
+    Ok(sign * magnitude)
+}
+
+fn callback_float<'src>(lex: &mut logos::Lexer<'src, Token<'src>>) -> Result<f64, LexingError> {
+    let slice = lex.slice().replace("_", "");
     slice.parse().map_err(LexingError::from)
 }
 
 #[derive(Logos, Debug, Clone, PartialEq)]
 #[logos(error = LexingError)]
-#[logos(skip r" ")]
-pub enum Token {
+#[logos(skip r"[ \t\r\n\f]+")]
+#[logos(skip r"#[^\n]*")]
+pub enum Token<'src> {
     Error(LexingError),
     #[regex(r"[\+-]?\d+", callback_integer, priority = 3)]
+    #[regex(r"[\+-]?0[xX][0-9a-fA-F][0-9a-fA-F_]*", callback_radix_integer, priority = 4)]
+    #[regex(r"[\+-]?0[oO][0-7][0-7_]*", callback_radix_integer, priority = 4)]
+    #[regex(r"[\+-]?0[bB][01][01_]*", callback_radix_integer, priority = 4)]
     Integer(isize),
-    #[regex(r"[\+-]?(\d+(\.\d*)?|\.\d+)([eE][\+-]?\d+)?", callback_float)]
+    #[regex(
+        r"[\+-]?(\d(_?\d)*(\.(\d(_?\d)*)?)?|\.\d(_?\d)*)([eE][\+-]?\d(_?\d)*)?",
+        callback_float
+    )]
     Float(f64),
-    #[regex(r"[_a-zA-Z\p{Greek}°][_a-zA-Z0-9\p{Greek}°]*|%", |lex| lex.slice().to_string())]
-    Name(String),
+    #[regex(r"[_a-zA-Z\p{Greek}°][_a-zA-Z0-9\p{Greek}°]*|%", |lex| lex.slice())]
+    Name(&'src str),
     #[token(".")]
     Dot,
     #[token("+")]
@@ -63,7 +88,7 @@ pub enum Token {
     Comma,
 }
 
-impl Display for Token {
+impl Display for Token<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Token::Integer(value) => write!(f, "Integer({})", value),
@@ -83,9 +108,158 @@ impl Display for Token {
     }
 }
 
-pub fn lex(input: &str) -> impl Iterator<Item = (Token, Span)> {
-    Token::lexer(input).spanned().map(|(tok, span)| match tok {
-        Ok(token) => (token, span),
-        Err(err) => (Token::Error(err), span),
-    })
+/// Owned counterpart of [`Token`].
+///
+/// [`Token::Name`] borrows directly from the lexed input, which ties a token to
+/// the lifetime of that slice. Callers that need to keep tokens around after the
+/// input is gone convert them with [`Token::into_owned`], turning the borrowed
+/// name into an owned `String`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OwnedToken {
+    Error(LexingError),
+    Integer(isize),
+    Float(f64),
+    Name(String),
+    Dot,
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Power,
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Token<'_> {
+    /// A stable, human-readable tag for this token's kind, e.g. `"float"`,
+    /// `"name"` or `"("`.
+    ///
+    /// Useful for tooling that wants to report "expected operator, found name"
+    /// without matching every variant.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Token::Error(_) => "error",
+            Token::Integer(_) => "integer",
+            Token::Float(_) => "float",
+            Token::Name(_) => "name",
+            Token::Dot => ".",
+            Token::Plus => "+",
+            Token::Minus => "-",
+            Token::Multiply => "*",
+            Token::Divide => "/",
+            Token::Power => "^",
+            Token::LParen => "(",
+            Token::RParen => ")",
+            Token::Comma => ",",
+        }
+    }
+
+    /// Convert this token into an [`OwnedToken`] that no longer borrows from the
+    /// input and therefore satisfies a `'static` lifetime.
+    pub fn into_owned(self) -> OwnedToken {
+        match self {
+            Token::Error(err) => OwnedToken::Error(err),
+            Token::Integer(value) => OwnedToken::Integer(value),
+            Token::Float(value) => OwnedToken::Float(value),
+            Token::Name(name) => OwnedToken::Name(name.to_string()),
+            Token::Dot => OwnedToken::Dot,
+            Token::Plus => OwnedToken::Plus,
+            Token::Minus => OwnedToken::Minus,
+            Token::Multiply => OwnedToken::Multiply,
+            Token::Divide => OwnedToken::Divide,
+            Token::Power => OwnedToken::Power,
+            Token::LParen => OwnedToken::LParen,
+            Token::RParen => OwnedToken::RParen,
+            Token::Comma => OwnedToken::Comma,
+        }
+    }
+}
+
+/// Incremental, pull-based lexer for interactive use such as autocomplete or
+/// syntax highlighting.
+///
+/// Wraps [`logos::Lexer`] to hand out one token at a time via
+/// [`Lexer::next_token`], with non-consuming lookahead through
+/// [`Lexer::peek_token`]. Lexing errors are surfaced in place as
+/// [`Token::Error`], mirroring [`lex`].
+#[derive(Clone)]
+pub struct Lexer<'src> {
+    inner: logos::Lexer<'src, Token<'src>>,
+}
+
+impl<'src> Lexer<'src> {
+    /// Create a lexer positioned at the start of `input`.
+    pub fn new(input: &'src str) -> Self {
+        Lexer {
+            inner: Token::lexer(input),
+        }
+    }
+
+    /// Consume and return the next token and its span, or `None` at end of input.
+    pub fn next_token(&mut self) -> Option<(Token<'src>, Span)> {
+        let token = self.inner.next()?;
+        Some((token.unwrap_or_else(Token::Error), self.inner.span()))
+    }
+
+    /// Return the next token without consuming it, by lexing a clone of the
+    /// internal cursor. Repeated calls yield the same token.
+    pub fn peek_token(&self) -> Option<(Token<'src>, Span)> {
+        let mut lookahead = self.inner.clone();
+        let token = lookahead.next()?;
+        Some((token.unwrap_or_else(Token::Error), lookahead.span()))
+    }
+}
+
+pub fn lex<'src>(input: &'src str) -> impl Iterator<Item = (Token<'src>, Span)> {
+    let mut lexer = Lexer::new(input);
+    std::iter::from_fn(move || lexer.next_token())
+}
+
+/// A lexing failure together with the location and text of the offending input.
+///
+/// Unlike [`Token::Error`], which smuggles the error back inside the token
+/// stream, a `LexDiagnostic` keeps *what* went wrong ([`LexDiagnostic::kind`])
+/// separate from *where* ([`LexDiagnostic::span`]). Its [`Display`] renders the
+/// offending substring underlined with a caret, so the error can be shown in
+/// place.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexDiagnostic {
+    pub kind: LexingError,
+    pub span: Span,
+    pub snippet: String,
+}
+
+impl Display for LexDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.snippet.chars().count().max(1);
+        writeln!(f, "  {}", self.snippet)?;
+        writeln!(f, "  {}", "^".repeat(width))?;
+        write!(f, "invalid token at column {}: {:?}", self.span.start, self.kind)
+    }
+}
+
+/// Lex `input`, collecting every failure with its location instead of leaving
+/// [`Token::Error`] markers in the stream.
+///
+/// Returns the full token stream on success, or every [`LexDiagnostic`] that
+/// occurred on failure.
+pub fn lex_checked(input: &str) -> Result<Vec<(Token<'_>, Span)>, Vec<LexDiagnostic>> {
+    let mut tokens = Vec::new();
+    let mut diagnostics = Vec::new();
+    for (result, span) in Token::lexer(input).spanned() {
+        match result {
+            Ok(token) => tokens.push((token, span)),
+            Err(kind) => diagnostics.push(LexDiagnostic {
+                kind,
+                snippet: input[span.clone()].to_string(),
+                span,
+            }),
+        }
+    }
+    if diagnostics.is_empty() {
+        Ok(tokens)
+    } else {
+        Err(diagnostics)
+    }
 }
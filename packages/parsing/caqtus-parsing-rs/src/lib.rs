@@ -1,6 +1,9 @@
 mod lexer;
 mod parser;
 
+pub use crate::lexer::{
+    lex, lex_checked, LexDiagnostic, Lexer, LexingError, OwnedToken, Token,
+};
 pub use crate::parser::{parse, ParseNode, BinaryOperator, UnaryOperator};
 
 extern crate uom;
@@ -1,4 +1,4 @@
-use crate::lexer::{Token, lex};
+use crate::lexer::{Token, lex_checked};
 use chumsky::error::Rich;
 use chumsky::input::{Input, Stream, ValueInput};
 use chumsky::pratt::{infix, left, prefix, right};
@@ -108,17 +108,17 @@ impl Display for ParseNode {
     }
 }
 
-fn identifier<'a, I>() -> impl Parser<'a, I, String, extra::Err<Rich<'a, Token>>> + Clone
+fn identifier<'a, I>() -> impl Parser<'a, I, String, extra::Err<Rich<'a, Token<'a>>>> + Clone
 where
-    I: ValueInput<'a, Token = Token, Span = SimpleSpan>,
+    I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
 {
     select! {
-        Token::Name(name) => vec![name],
+        Token::Name(name) => vec![name.to_string()],
     }
     .foldl(
         just(Token::Dot)
             .ignore_then(select! {
-                Token::Name(name) => name,
+                Token::Name(name) => name.to_string(),
             })
             .repeated(),
         |mut lhs, name| {
@@ -129,9 +129,9 @@ where
     .map(|names| names.join("."))
 }
 
-fn atom<'a, I>() -> impl Parser<'a, I, ParseNode, extra::Err<Rich<'a, Token>>> + Clone
+fn atom<'a, I>() -> impl Parser<'a, I, ParseNode, extra::Err<Rich<'a, Token<'a>>>> + Clone
 where
-    I: ValueInput<'a, Token = Token, Span = SimpleSpan>,
+    I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
 {
     let number = select! {
         Token::Integer(value) = e => ParseNode::Integer{value, span: e.span()},
@@ -141,7 +141,7 @@ where
         Token::Integer(value) => value as f64,
         Token::Float(value) => value,
     }
-    .then(select! {Token::Name(unit) => unit})
+    .then(select! {Token::Name(unit) => unit.to_string()})
     .map_with(|(value, unit), e| ParseNode::Quantity {
         value,
         unit,
@@ -155,9 +155,9 @@ where
         }))
 }
 
-fn parser<'a, I>() -> impl Parser<'a, I, ParseNode, extra::Err<Rich<'a, Token>>>
+fn parser<'a, I>() -> impl Parser<'a, I, ParseNode, extra::Err<Rich<'a, Token<'a>>>>
 where
-    I: ValueInput<'a, Token = Token, Span = SimpleSpan>,
+    I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
 {
     recursive(|expr| {
         let call = identifier()
@@ -237,8 +237,20 @@ where
     .then_ignore(end())
 }
 
-pub fn parse(input: &str) -> Result<ParseNode, Vec<Rich<Token>>> {
-    let token_iter = lex(input).map(|(token, span)| (token, span.into()));
+pub fn parse(input: &str) -> Result<ParseNode, Vec<Rich<'_, Token<'_>>>> {
+    let tokens = match lex_checked(input) {
+        Ok(tokens) => tokens,
+        Err(diagnostics) => {
+            return Err(diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    let message = diagnostic.to_string();
+                    Rich::custom(diagnostic.span.into(), message)
+                })
+                .collect());
+        }
+    };
+    let token_iter = tokens.into_iter().map(|(token, span)| (token, span.into()));
     let token_stream =
         Stream::from_iter(token_iter).map((0..input.len()).into(), |(t, s): (_, _)| (t, s));
     parser().parse(token_stream).into_result()
@@ -0,0 +1,27 @@
+use caqtus_parsing_rs::{Lexer, Token};
+
+#[test]
+fn peek_does_not_advance_the_cursor() {
+    let mut lexer = Lexer::new("10 MHz");
+    let peeked = lexer.peek_token();
+    let consumed = lexer.next_token();
+    assert_eq!(peeked, consumed);
+    assert_eq!(consumed, Some((Token::Integer(10), 0..2)));
+}
+
+#[test]
+fn next_token_walks_the_whole_input() {
+    let mut lexer = Lexer::new("a + 1");
+    let mut kinds = Vec::new();
+    while let Some((token, _)) = lexer.next_token() {
+        kinds.push(token.kind());
+    }
+    assert_eq!(kinds, vec!["name", "+", "integer"]);
+}
+
+#[test]
+fn peek_token_is_none_at_end_of_input() {
+    let mut lexer = Lexer::new("1");
+    lexer.next_token();
+    assert_eq!(lexer.peek_token(), None);
+}
@@ -0,0 +1,31 @@
+use caqtus_parsing_rs::{lex_checked, parse, LexingError};
+
+#[test]
+fn lex_checked_returns_tokens_when_input_is_valid() {
+    let tokens = lex_checked("10 MHz").unwrap();
+    assert_eq!(tokens.len(), 2);
+}
+
+#[test]
+fn lex_checked_collects_every_invalid_token() {
+    let diagnostics = lex_checked("1 @ 2 $").unwrap_err();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].kind, LexingError::InvalidToken);
+    assert_eq!(diagnostics[0].snippet, "@");
+    assert_eq!(diagnostics[0].span, 2..3);
+    assert_eq!(diagnostics[1].snippet, "$");
+}
+
+#[test]
+fn diagnostic_display_points_at_the_offending_column() {
+    let diagnostics = lex_checked("1 @").unwrap_err();
+    let rendered = diagnostics[0].to_string();
+    assert!(rendered.contains("^"));
+    assert!(rendered.contains("column 2"));
+}
+
+#[test]
+fn parse_surfaces_a_precise_lexing_error() {
+    let errors = parse("1 @ 2").unwrap_err();
+    assert!(errors[0].to_string().contains("column 2"));
+}
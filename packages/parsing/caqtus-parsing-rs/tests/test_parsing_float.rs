@@ -51,3 +51,32 @@ fn successfully_parse_float_string_with_exponent_and_sign() {
         span: (0..8).into()
     });
 }
+
+#[test]
+fn successfully_parse_float_string_with_digit_separators() {
+    let result = parse("1_000.000_5").unwrap();
+    assert_eq!(
+        result,
+        ParseNode::Float {
+            value: 1000.0005,
+            span: (0..11).into()
+        }
+    );
+}
+
+#[test]
+fn successfully_parse_float_string_with_separators_in_exponent() {
+    assert_eq!(parse("6_022e23").unwrap().to_string(), "6.022e26");
+}
+
+#[test]
+fn successfully_parse_quantity_with_separators() {
+    assert_eq!(parse("1_000.000_5 kHz").unwrap().to_string(), "1000.0005 kHz");
+}
+
+#[test]
+fn does_not_absorb_separator_adjacent_to_dot_into_float() {
+    // A trailing `_` right before the decimal point is not part of the float
+    // literal: it is lexed as a name, so the parse fails.
+    assert!(parse("1_.5 + 2").is_err());
+}
@@ -54,3 +54,62 @@ fn fails_to_parse_number_seperated_by_space() {
     let result = parse("45 0");
     assert!(result.is_err());
 }
+
+#[test]
+fn successfully_parse_hexadecimal_integer() {
+    let result = parse("0xFF").unwrap();
+    assert_eq!(
+        result,
+        ParseNode::Integer {
+            value: 255,
+            span: (0..4).into(),
+        }
+    );
+}
+
+#[test]
+fn successfully_parse_octal_integer() {
+    let result = parse("0o17").unwrap();
+    assert_eq!(
+        result,
+        ParseNode::Integer {
+            value: 15,
+            span: (0..4).into(),
+        }
+    );
+}
+
+#[test]
+fn successfully_parse_binary_integer() {
+    let result = parse("0b1010").unwrap();
+    assert_eq!(
+        result,
+        ParseNode::Integer {
+            value: 10,
+            span: (0..6).into(),
+        }
+    );
+}
+
+#[test]
+fn successfully_parse_hexadecimal_integer_with_separators() {
+    assert_eq!(parse("0xFF_FF").unwrap().to_string(), "65535");
+}
+
+#[test]
+fn successfully_parse_hexadecimal_quantity() {
+    assert_eq!(parse("0xFF MHz").unwrap().to_string(), "255.0 MHz");
+}
+
+#[test]
+fn successfully_parse_signed_hexadecimal_integer() {
+    let result = parse("-0xFF").unwrap();
+    assert_eq!(
+        result,
+        ParseNode::Integer {
+            value: -255,
+            span: (0..5).into(),
+        }
+    );
+    assert_eq!(parse("+0b1010").unwrap().to_string(), "10");
+}
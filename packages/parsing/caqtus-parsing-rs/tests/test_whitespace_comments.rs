@@ -0,0 +1,32 @@
+use caqtus_parsing_rs::{ParseNode, parse};
+
+#[test]
+fn skips_tabs_and_newlines_between_tokens() {
+    assert_eq!(parse("1\t+\n2").unwrap().to_string(), "(1 + 2)");
+}
+
+#[test]
+fn skips_trailing_line_comment() {
+    assert_eq!(parse("10 MHz # carrier frequency").unwrap().to_string(), "10.0 MHz");
+}
+
+#[test]
+fn skips_comment_on_its_own_line() {
+    assert_eq!(
+        parse("# amplitude\n1 + 2").unwrap().to_string(),
+        "(1 + 2)"
+    );
+}
+
+#[test]
+fn span_after_skipped_region_is_correct() {
+    let result = parse("  \t45").unwrap();
+    assert_eq!(result.to_string(), "45");
+    assert_eq!(
+        result,
+        ParseNode::Integer {
+            value: 45,
+            span: (3..5).into(),
+        }
+    );
+}